@@ -0,0 +1,283 @@
+use crate::errors::CCIPReadMiddlewareError;
+use crate::types::{RPCCall, RPCResponse};
+use ethers_core::abi::{self, ParamType, Token};
+use ethers_core::types::transaction::eip2718::TypedTransaction;
+use ethers_core::types::{Address, Bytes, NameOrAddress};
+use ethers_core::utils::hex;
+use ethers_providers::{Middleware, ProviderError, RpcError};
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Selector of the `OffchainLookup(address,string[],bytes,bytes4,bytes)`
+/// error defined by EIP-3668.
+const OFFCHAIN_LOOKUP_SELECTOR: [u8; 4] = [0x55, 0x6f, 0x18, 0x30];
+
+/// Executes the full EIP-3668 CCIP-Read round trip against a `Middleware`:
+/// send the initial call, catch an `OffchainLookup` revert, fetch the
+/// result from the gateway URLs it carries, and submit the resulting
+/// callback call.
+pub struct CCIPReadClient<M> {
+    provider: M,
+    http: reqwest::Client,
+}
+
+impl<M> CCIPReadClient<M>
+where
+    M: Middleware,
+    M::Error: std::error::Error + 'static,
+{
+    /// Wrap a provider with CCIP-Read resolution.
+    pub fn new(provider: M) -> Self {
+        CCIPReadClient {
+            provider,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Call `to` with `data`, transparently resolving an `OffchainLookup`
+    /// revert via the gateway URLs it carries if the target raises one.
+    pub async fn call(&self, to: Address, data: Bytes) -> Result<Bytes, CCIPReadMiddlewareError> {
+        match self.provider.call(&build_tx(to, data.clone()), None).await {
+            Ok(result) => Ok(result),
+            Err(err) => {
+                let revert_data = extract_revert_data(&err)
+                    .ok_or_else(|| CCIPReadMiddlewareError::Provider(err.to_string()))?;
+                self.resolve_offchain_lookup(to, revert_data).await
+            }
+        }
+    }
+
+    async fn resolve_offchain_lookup(
+        &self,
+        sender: Address,
+        revert_data: Bytes,
+    ) -> Result<Bytes, CCIPReadMiddlewareError> {
+        if revert_data.len() < 4 || revert_data[0..4] != OFFCHAIN_LOOKUP_SELECTOR {
+            return Err(CCIPReadMiddlewareError::UnsupportedRevert(hex::encode(
+                &revert_data,
+            )));
+        }
+
+        let tokens = abi::decode(
+            &[
+                ParamType::Address,
+                ParamType::Array(Box::new(ParamType::String)),
+                ParamType::Bytes,
+                ParamType::FixedBytes(4),
+                ParamType::Bytes,
+            ],
+            &revert_data[4..],
+        )?;
+        let [lookup_sender, urls, call_data, callback_function, extra_data]: [Token; 5] = tokens
+            .try_into()
+            .expect("abi::decode returns one token per requested ParamType");
+
+        let lookup_sender = lookup_sender.into_address().expect("ParamType::Address");
+        if lookup_sender != sender {
+            return Err(CCIPReadMiddlewareError::UnsupportedRevert(hex::encode(
+                &revert_data,
+            )));
+        }
+        let urls: Vec<String> = urls
+            .into_array()
+            .expect("ParamType::Array")
+            .into_iter()
+            .map(|url| url.into_string().expect("ParamType::String"))
+            .collect();
+        let call_data = Bytes::from(call_data.into_bytes().expect("ParamType::Bytes"));
+        let callback_function = callback_function
+            .into_fixed_bytes()
+            .expect("ParamType::FixedBytes(4)");
+        let extra_data = Bytes::from(extra_data.into_bytes().expect("ParamType::Bytes"));
+
+        let response = self
+            .fetch_from_gateways(&urls, lookup_sender, &call_data)
+            .await?;
+
+        let mut callback_calldata = callback_function;
+        callback_calldata.extend(ethers_core::abi::encode(&[
+            Token::Bytes(response.to_vec()),
+            Token::Bytes(extra_data.to_vec()),
+        ]));
+
+        self.provider
+            .call(&build_tx(sender, Bytes::from(callback_calldata)), None)
+            .await
+            .map_err(|err| CCIPReadMiddlewareError::Provider(err.to_string()))
+    }
+
+    /// Try each gateway URL in order, substituting `{sender}`/`{data}`, until
+    /// one returns a 2xx response.
+    async fn fetch_from_gateways(
+        &self,
+        urls: &[String],
+        sender: Address,
+        call_data: &Bytes,
+    ) -> Result<Bytes, CCIPReadMiddlewareError> {
+        let sender = format!("{sender:?}");
+        let data = format!("0x{}", hex::encode(call_data));
+
+        for url in urls {
+            let request = if url.contains("{data}") {
+                let url = url.replace("{sender}", &sender).replace("{data}", &data);
+                self.http.get(url)
+            } else {
+                let url = url.replace("{sender}", &sender);
+                self.http.post(url).json(&RPCCall {
+                    to: sender.clone(),
+                    data: call_data.clone(),
+                })
+            };
+
+            let Ok(response) = request.send().await else {
+                continue;
+            };
+            let status = response.status().as_u16();
+            let Ok(body) = response.json::<Value>().await else {
+                continue;
+            };
+            let response = RPCResponse { status, body };
+
+            if (200..300).contains(&response.status) {
+                if let Some(data) = response.body.get("data").and_then(Value::as_str) {
+                    return Bytes::from_str(data).map_err(CCIPReadMiddlewareError::from);
+                }
+            }
+        }
+
+        Err(CCIPReadMiddlewareError::GatewayUnavailable)
+    }
+}
+
+fn build_tx(to: Address, data: Bytes) -> TypedTransaction {
+    let mut tx = TypedTransaction::default();
+    tx.set_to(NameOrAddress::Address(to));
+    tx.set_data(data);
+    tx
+}
+
+/// Pull the raw revert bytes out of a provider error, if the underlying
+/// JSON-RPC error response carried any (as is the case for an
+/// `OffchainLookup` revert).
+fn extract_revert_data<E: std::error::Error + 'static>(err: &E) -> Option<Bytes> {
+    let provider_err = (err as &dyn std::error::Error).downcast_ref::<ProviderError>()?;
+    let ProviderError::JsonRpcClientError(err) = provider_err else {
+        return None;
+    };
+    let data = err.as_error_response()?.data.as_ref()?.as_str()?;
+    Bytes::from_str(data).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers_providers::{Http, Provider};
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, path_regex};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_client() -> CCIPReadClient<Provider<Http>> {
+        let provider = Provider::<Http>::try_from("http://localhost:8545").unwrap();
+        CCIPReadClient::new(provider)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_gateways_uses_get_when_data_placeholder_present() {
+        let mock_server = MockServer::start().await;
+        let sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let call_data = Bytes::from_str("0x12345678").unwrap();
+
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/lookup/.*/0x12345678\.json$"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": "0xdeadbeef" })))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/lookup/{{sender}}/{{data}}.json", mock_server.uri());
+        let result = test_client()
+            .fetch_from_gateways(&[url], sender, &call_data)
+            .await
+            .unwrap();
+        assert_eq!(result, Bytes::from_str("0xdeadbeef").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_gateways_posts_rpc_call_when_no_data_placeholder() {
+        let mock_server = MockServer::start().await;
+        let sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let call_data = Bytes::from_str("0x12345678").unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .and(body_json(json!({
+                "sender": format!("{sender:?}"),
+                "data": "0x12345678",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": "0xcafebabe" })))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/lookup", mock_server.uri());
+        let result = test_client()
+            .fetch_from_gateways(&[url], sender, &call_data)
+            .await
+            .unwrap();
+        assert_eq!(result, Bytes::from_str("0xcafebabe").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_from_gateways_errors_when_all_urls_fail() {
+        let mock_server = MockServer::start().await;
+        let sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let call_data = Bytes::from_str("0x12345678").unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/lookup"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/lookup", mock_server.uri());
+        let err = test_client()
+            .fetch_from_gateways(&[url], sender, &call_data)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CCIPReadMiddlewareError::GatewayUnavailable));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_offchain_lookup_rejects_non_offchain_lookup_revert() {
+        let sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let revert_data = Bytes::from_str("0xdeadbeef").unwrap();
+
+        let err = test_client()
+            .resolve_offchain_lookup(sender, revert_data)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CCIPReadMiddlewareError::UnsupportedRevert(_)));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_offchain_lookup_rejects_sender_mismatch() {
+        let called_sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let lookup_sender =
+            Address::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let mut revert_data = OFFCHAIN_LOOKUP_SELECTOR.to_vec();
+        revert_data.extend(ethers_core::abi::encode(&[
+            Token::Address(lookup_sender),
+            Token::Array(vec![Token::String(
+                "https://example.com/{sender}/{data}.json".to_string(),
+            )]),
+            Token::Bytes(vec![0x12, 0x34, 0x56, 0x78]),
+            Token::FixedBytes(vec![0xaa, 0xbb, 0xcc, 0xdd]),
+            Token::Bytes(Vec::new()),
+        ]));
+
+        let err = test_client()
+            .resolve_offchain_lookup(called_sender, Bytes::from(revert_data))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CCIPReadMiddlewareError::UnsupportedRevert(_)));
+    }
+}
@@ -0,0 +1,59 @@
+use axum::http::{header::CONTENT_TYPE, HeaderName, HeaderValue, Method};
+use std::time::Duration;
+use tower_http::cors::{AllowHeaders, AllowOrigin, CorsLayer};
+
+/// Default preflight cache duration applied by [`CorsConfig::default`].
+const DEFAULT_MAX_AGE: Duration = Duration::from_secs(3600);
+
+/// CORS policy applied to the gateway routes by [`crate::Server::with_cors`].
+///
+/// Defaults to a permissive policy (any origin, `GET`/`POST`/`OPTIONS`,
+/// `Content-Type` request header) suitable for a public, read-only CCIP-Read
+/// gateway queried directly from dApp frontends. The `Content-Type` default
+/// is required for the JSON `POST /gateway` route to clear a browser's CORS
+/// preflight.
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    allow_origin: AllowOrigin,
+    allow_headers: AllowHeaders,
+    max_age: Duration,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        CorsConfig {
+            allow_origin: AllowOrigin::any(),
+            allow_headers: AllowHeaders::list([CONTENT_TYPE]),
+            max_age: DEFAULT_MAX_AGE,
+        }
+    }
+}
+
+impl CorsConfig {
+    /// Restrict the allowed origins instead of the permissive default.
+    pub fn with_allowed_origins(mut self, origins: Vec<HeaderValue>) -> Self {
+        self.allow_origin = AllowOrigin::list(origins);
+        self
+    }
+
+    /// Restrict the allowed request headers instead of the default, which
+    /// permits only `Content-Type`.
+    pub fn with_allowed_headers(mut self, headers: Vec<HeaderName>) -> Self {
+        self.allow_headers = AllowHeaders::list(headers);
+        self
+    }
+
+    /// Override the default 1 hour preflight cache duration.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    pub(crate) fn into_layer(self) -> CorsLayer {
+        CorsLayer::new()
+            .allow_origin(self.allow_origin)
+            .allow_headers(self.allow_headers)
+            .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+            .max_age(self.max_age)
+    }
+}
@@ -15,4 +15,22 @@ pub enum CCIPReadMiddlewareError /*<M: Middleware>*/ {
 
     #[error("Parse bytes error")]
     ParseBytes(#[from] ethers_core::types::ParseBytesError),
+
+    #[error("Invalid sender address")]
+    InvalidAddress,
+
+    #[error("Signing error")]
+    Signing(#[from] k256::ecdsa::Error),
+
+    #[error("Provider error: {0}")]
+    Provider(String),
+
+    #[error("Call reverted with an unsupported error (not an OffchainLookup from the called contract): 0x{0}")]
+    UnsupportedRevert(String),
+
+    #[error("All CCIP-Read gateway URLs failed or returned an error response")]
+    GatewayUnavailable,
+
+    #[error("Handler requires a signed response but the server has no signer configured")]
+    MissingSigner,
 }
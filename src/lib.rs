@@ -0,0 +1,11 @@
+mod client;
+mod cors;
+mod errors;
+mod server;
+mod types;
+
+pub use client::CCIPReadClient;
+pub use cors::CorsConfig;
+pub use errors::CCIPReadMiddlewareError;
+pub use server::Server;
+pub use types::{CCIPReadHandler, RPCCall, RPCResponse};
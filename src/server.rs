@@ -1,27 +1,50 @@
 use crate::types::{CCIPReadHandler, RPCCall, RPCResponse};
-use crate::CCIPReadMiddlewareError;
+use crate::{CCIPReadMiddlewareError, CorsConfig};
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
-    response::IntoResponse,
+    http::{
+        header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH},
+        HeaderMap, StatusCode,
+    },
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
-use ethers_core::abi::{Abi, Function};
-use ethers_core::utils::hex;
+use ethers_core::abi::{self, Abi, Function, ParamType, Token};
+use ethers_core::types::{Address, Bytes, U256};
+use ethers_core::utils::{hex, keccak256};
+use k256::ecdsa::SigningKey;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tower_http::trace::TraceLayer;
 use tracing::debug;
 
 type Handlers = HashMap<[u8; 4], (Function, Arc<dyn CCIPReadHandler + Sync + Send>)>;
 
+/// Default validity window for signed responses, matching the ENS
+/// offchain-resolver gateway reference implementation.
+const DEFAULT_SIGNATURE_TTL: Duration = Duration::from_secs(300);
+
+/// Selector of the standardized batch-gateway entry point,
+/// `query((address,bytes,bytes)[])`.
+const BATCH_GATEWAY_SELECTOR: [u8; 4] = [0x65, 0xb7, 0x1a, 0x64];
+
+#[derive(Clone)]
+struct SigningConfig {
+    signing_key: SigningKey,
+    ttl: Duration,
+}
+
 struct AppState {
     handlers: Handlers,
+    signing: Option<SigningConfig>,
+    timeout: Option<Duration>,
+    batch_gateway: bool,
 }
 
 /// CCIP-Read Server.
@@ -30,12 +53,16 @@ pub struct Server {
     ip_address: IpAddr,
     port: u16,
     handlers: Handlers,
+    signing: Option<SigningConfig>,
+    cors: Option<CorsConfig>,
+    timeout: Option<Duration>,
+    batch_gateway: bool,
 }
 
 #[derive(Deserialize)]
 pub struct CCIPReadMiddlewareRequest {
     sender: String,
-    calldata: String,
+    data: String,
 }
 
 impl Server {
@@ -49,9 +76,73 @@ impl Server {
             ip_address,
             port,
             handlers: HashMap::new(),
+            signing: None,
+            cors: None,
+            timeout: None,
+            batch_gateway: false,
         }
     }
 
+    /// Serve the standardized CCIP-Read batch-gateway entry point,
+    /// `query((address,bytes,bytes)[])`, at the existing `/gateway` routes.
+    /// It dispatches each inner `callData` through the normal
+    /// selector→handler map and returns `(bool[] failures, bytes[]
+    /// responses)`, marking `failures[i] = true` for entries whose handler
+    /// is missing or errors rather than aborting the whole batch. This lets
+    /// clients resolve several independent lookups in one round trip.
+    pub fn with_batch_gateway(mut self) -> Self {
+        self.batch_gateway = true;
+        self
+    }
+
+    /// Bound how long a handler may take to resolve a single call. If it
+    /// doesn't complete in time, the gateway responds with HTTP 408 instead
+    /// of leaving the connection hanging, so a slow or stuck handler (e.g.
+    /// one doing its own network I/O) can't exhaust server resources.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Enable CORS on the gateway routes, letting browsers call them
+    /// directly (e.g. a dApp frontend using `fetch`). Without this,
+    /// cross-origin requests fail the preflight `OPTIONS` check.
+    ///
+    /// # Arguments
+    /// * `cors` the CORS policy to apply; see [`CorsConfig`] for its
+    ///   permissive-by-default settings
+    pub fn with_cors(mut self, cors: CorsConfig) -> Self {
+        self.cors = Some(cors);
+        self
+    }
+
+    /// Enable signing of responses from handlers that opt in via
+    /// [`CCIPReadHandler::signed`], producing
+    /// `abi.encode(bytes result, uint64 expires, bytes signature)` instead of
+    /// the raw handler result, as required by `OffchainResolver.resolveWithProof`.
+    ///
+    /// Signatures are valid for [`DEFAULT_SIGNATURE_TTL`] (300s) unless
+    /// overridden with [`Server::with_signature_ttl`].
+    ///
+    /// # Arguments
+    /// * `signing_key` the secp256k1 key used to sign responses
+    pub fn with_signer(mut self, signing_key: SigningKey) -> Self {
+        self.signing = Some(SigningConfig {
+            signing_key,
+            ttl: DEFAULT_SIGNATURE_TTL,
+        });
+        self
+    }
+
+    /// Override the default signature validity window set by
+    /// [`Server::with_signer`].
+    pub fn with_signature_ttl(mut self, ttl: Duration) -> Self {
+        if let Some(signing) = self.signing.as_mut() {
+            signing.ttl = ttl;
+        }
+        self
+    }
+
     /// Add callbacks for CCIP-Read server requests
     ///
     /// # Arguments
@@ -95,68 +186,133 @@ impl Server {
     fn router(&self) -> Router {
         let shared_state = Arc::new(AppState {
             handlers: self.handlers.clone(),
+            signing: self.signing.clone(),
+            timeout: self.timeout,
+            batch_gateway: self.batch_gateway,
         });
-        Router::new()
+        let mut router = Router::new()
             .route("/gateway/:sender/:calldata", get(gateway_get))
             .route("/gateway", post(gateway_post))
             .with_state(shared_state)
-            .layer(TraceLayer::new_for_http())
+            .layer(TraceLayer::new_for_http());
+        if let Some(cors) = self.cors.clone() {
+            router = router.layer(cors.into_layer());
+        }
+        router
     }
 }
 
 async fn gateway_get(
     Path((sender, calldata)): Path<(String, String)>,
     State(app_state): State<Arc<AppState>>,
-) -> Result<impl IntoResponse, StatusCode> {
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
     let calldata = String::from(calldata.strip_suffix(".json").unwrap_or(calldata.as_str()));
     debug!("Should handle sender={:?} calldata={}", sender, calldata);
 
     if let Ok(calldata) = ethers_core::types::Bytes::from_str(&calldata.as_str()[2..]) {
-        let response = call(
+        let ttl = handler_ttl(&app_state.handlers, &calldata);
+        let response = match call(
             RPCCall {
                 to: sender.clone(),
                 data: calldata,
             },
             app_state.handlers.clone(),
+            app_state.signing.clone(),
+            app_state.timeout,
+            app_state.batch_gateway,
         )
         .await
-        .unwrap();
+        {
+            Ok(response) => response,
+            Err(err) => {
+                let body = json!({ "message": err.to_string() });
+                return Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response());
+            }
+        };
 
+        let status = if response.status == 408 {
+            StatusCode::REQUEST_TIMEOUT
+        } else {
+            StatusCode::OK
+        };
         let body = response.body;
-        Ok((StatusCode::OK, Json(body)))
+
+        if let Some(ttl) = ttl.filter(|_| status == StatusCode::OK) {
+            let etag = format!("\"{}\"", hex::encode(keccak256(body.to_string())));
+            if headers.get(IF_NONE_MATCH).and_then(|v| v.to_str().ok()) == Some(etag.as_str()) {
+                let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+                not_modified
+                    .headers_mut()
+                    .insert(ETAG, etag.parse().unwrap());
+                return Ok(not_modified);
+            }
+
+            let mut response = (status, Json(body)).into_response();
+            response.headers_mut().insert(
+                CACHE_CONTROL,
+                format!("max-age={}", ttl.as_secs()).parse().unwrap(),
+            );
+            response.headers_mut().insert(ETAG, etag.parse().unwrap());
+            return Ok(response);
+        }
+
+        Ok((status, Json(body)).into_response())
     } else {
         let error_message: Value = json!({
             "message": "Unexpected error",
         });
-        Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_message)))
+        Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_message)).into_response())
     }
 }
 
+/// Look up the declared [`CCIPReadHandler::ttl`] for the handler that would
+/// serve `calldata`, if any is registered for its selector.
+fn handler_ttl(handlers: &Handlers, calldata: &[u8]) -> Option<Duration> {
+    let selector = calldata.get(0..4)?;
+    handlers.get(selector).and_then(|(_, handler)| handler.ttl())
+}
+
 async fn gateway_post(
     State(app_state): State<Arc<AppState>>,
-    Json(data): Json<CCIPReadMiddlewareRequest>,
+    Json(request): Json<CCIPReadMiddlewareRequest>,
 ) -> Result<impl IntoResponse, StatusCode> {
-    let sender = data.sender;
+    let sender = request.sender;
     let calldata = String::from(
-        data.calldata
+        request
+            .data
             .strip_suffix(".json")
-            .unwrap_or(data.calldata.as_str()),
+            .unwrap_or(request.data.as_str()),
     );
     debug!("Should handle sender={:?} calldata={}", sender, calldata);
 
     if let Ok(calldata) = ethers_core::types::Bytes::from_str(&calldata.as_str()[2..]) {
-        let response = call(
+        let response = match call(
             RPCCall {
-                to: sender.clone(),
+                to: sender,
                 data: calldata,
             },
             app_state.handlers.clone(),
+            app_state.signing.clone(),
+            app_state.timeout,
+            app_state.batch_gateway,
         )
         .await
-        .unwrap();
+        {
+            Ok(response) => response,
+            Err(err) => {
+                let body = json!({ "message": err.to_string() });
+                return Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(body)));
+            }
+        };
 
+        let status = if response.status == 408 {
+            StatusCode::REQUEST_TIMEOUT
+        } else {
+            StatusCode::OK
+        };
         let body = response.body;
-        Ok((StatusCode::OK, Json(body)))
+        Ok((status, Json(body)))
     } else {
         let error_message: Value = json!({
             "message": "Unexpected error",
@@ -169,10 +325,20 @@ async fn gateway_post(
     name = "ccip_server"
     skip_all
 )]
-async fn call(call: RPCCall, handlers: Handlers) -> Result<RPCResponse, CCIPReadMiddlewareError> {
+async fn call(
+    call: RPCCall,
+    handlers: Handlers,
+    signing: Option<SigningConfig>,
+    timeout: Option<Duration>,
+    batch_gateway: bool,
+) -> Result<RPCResponse, CCIPReadMiddlewareError> {
     debug!("Received call with {:?}", call);
     let selector = &call.data[0..4];
 
+    if batch_gateway && selector == BATCH_GATEWAY_SELECTOR && !handlers.contains_key(selector) {
+        return call_batch(call, handlers, signing, timeout).await;
+    }
+
     // find a function handler for this selector
     let handler = if let Some(handler) = handlers.get(selector) {
         handler
@@ -189,17 +355,41 @@ async fn call(call: RPCCall, handlers: Handlers) -> Result<RPCResponse, CCIPRead
     let args = handler.0.decode_input(&call.data[4..])?;
 
     let callback = handler.1.clone();
-    if let Ok(tokens) = callback
-        .call(
-            args,
-            RPCCall {
-                to: call.to,
-                data: call.data,
-            },
-        )
-        .await
-    {
-        let encoded_data = ethers_core::abi::encode(&tokens);
+    let target = call.to.clone();
+    let request_calldata = call.data.clone();
+    let handler_call = callback.call(
+        args,
+        RPCCall {
+            to: call.to,
+            data: call.data,
+        },
+    );
+    let result = match timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, handler_call).await {
+            Ok(result) => result,
+            Err(_) => {
+                return Ok(RPCResponse {
+                    status: 408,
+                    body: json!({
+                        "message": "Gateway timeout",
+                    }),
+                });
+            }
+        },
+        None => handler_call.await,
+    };
+
+    if let Ok(tokens) = result {
+        let encoded_result = ethers_core::abi::encode(&tokens);
+
+        let encoded_data = if callback.signed() {
+            let signing = signing
+                .as_ref()
+                .ok_or(CCIPReadMiddlewareError::MissingSigner)?;
+            sign_response(signing, &target, &request_calldata, &encoded_result)?
+        } else {
+            encoded_result
+        };
         let encoded_data = format!("0x{}", hex::encode(encoded_data));
         debug!("Final encoded data: {}", encoded_data);
 
@@ -219,6 +409,126 @@ async fn call(call: RPCCall, handlers: Handlers) -> Result<RPCResponse, CCIPRead
     }
 }
 
+/// Handle the standardized batch-gateway entry point,
+/// `query((address,bytes,bytes)[])`: dispatch each `(sender, urls, callData)`
+/// entry's `callData` through the normal selector→handler map, collecting
+/// `(bool[] failures, bytes[] responses)` instead of aborting the whole
+/// batch on the first missing handler or error.
+fn call_batch(
+    request: RPCCall,
+    handlers: Handlers,
+    signing: Option<SigningConfig>,
+    timeout: Option<Duration>,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<RPCResponse, CCIPReadMiddlewareError>> + Send>>
+{
+    Box::pin(async move {
+        let tokens = abi::decode(
+            &[ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Address,
+                ParamType::Bytes,
+                ParamType::Bytes,
+            ])))],
+            &request.data[4..],
+        )?;
+        let [queries]: [Token; 1] = tokens
+            .try_into()
+            .expect("abi::decode returns one token per requested ParamType");
+        let queries = queries.into_array().expect("ParamType::Array");
+
+        let mut failures = Vec::with_capacity(queries.len());
+        let mut responses = Vec::with_capacity(queries.len());
+
+        for query in queries {
+            let entry = query
+                .into_tuple()
+                .and_then(|fields| <[Token; 3]>::try_from(fields).ok());
+            let Some([sender, _urls, inner_calldata]) = entry else {
+                failures.push(Token::Bool(true));
+                responses.push(Token::Bytes(Vec::new()));
+                continue;
+            };
+            let sender = sender.into_address().expect("ParamType::Address");
+            let inner_calldata = Bytes::from(inner_calldata.into_bytes().expect("ParamType::Bytes"));
+            if inner_calldata.len() < 4 {
+                failures.push(Token::Bool(true));
+                responses.push(Token::Bytes(Vec::new()));
+                continue;
+            }
+
+            let inner_call = RPCCall {
+                to: format!("{sender:?}"),
+                data: inner_calldata,
+            };
+            let result = call(inner_call, handlers.clone(), signing.clone(), timeout, false).await;
+
+            let response_data = match result {
+                Ok(response) if response.status == 200 => response
+                    .body
+                    .get("data")
+                    .and_then(Value::as_str)
+                    .and_then(|data| Bytes::from_str(&data[2..]).ok()),
+                _ => None,
+            };
+
+            match response_data {
+                Some(data) => {
+                    failures.push(Token::Bool(false));
+                    responses.push(Token::Bytes(data.to_vec()));
+                }
+                None => {
+                    failures.push(Token::Bool(true));
+                    responses.push(Token::Bytes(Vec::new()));
+                }
+            }
+        }
+
+        let encoded = ethers_core::abi::encode(&[Token::Array(failures), Token::Array(responses)]);
+        Ok(RPCResponse {
+            status: 200,
+            body: json!({
+                "data": format!("0x{}", hex::encode(encoded)),
+            }),
+        })
+    })
+}
+
+/// Sign a handler's ABI-encoded result per the ENS offchain-resolver
+/// `SignatureVerifier` scheme, returning
+/// `abi.encode(bytes result, uint64 expires, bytes signature)`.
+fn sign_response(
+    signing: &SigningConfig,
+    target: &str,
+    request_calldata: &[u8],
+    encoded_result: &[u8],
+) -> Result<Vec<u8>, CCIPReadMiddlewareError> {
+    let target = Address::from_str(target).map_err(|_| CCIPReadMiddlewareError::InvalidAddress)?;
+    let expires = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .checked_add(signing.ttl)
+        .unwrap_or_default()
+        .as_secs();
+
+    let mut message = Vec::with_capacity(2 + 20 + 8 + 32 + 32);
+    message.extend_from_slice(&[0x19, 0x00]);
+    message.extend_from_slice(target.as_bytes());
+    message.extend_from_slice(&expires.to_be_bytes());
+    message.extend_from_slice(&keccak256(request_calldata));
+    message.extend_from_slice(&keccak256(encoded_result));
+    let message_hash = keccak256(message);
+
+    let (signature, recovery_id) = signing.signing_key.sign_prehash_recoverable(&message_hash)?;
+    let mut signature_bytes = [0u8; 65];
+    signature_bytes[..64].copy_from_slice(&signature.to_bytes());
+    signature_bytes[64] = recovery_id.to_byte() + 27;
+
+    Ok(ethers_core::abi::encode(&[
+        Token::Bytes(encoded_result.to_vec()),
+        Token::Uint(U256::from(expires)),
+        Token::Bytes(signature_bytes.to_vec()),
+    ]))
+}
+
 // Sample ENS offchain resolver request:
 // http://localhost:8080/gateway/0x8464135c8f25da09e49bc8782676a84730c318bc/0x9061b92300000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000000a047465737403657468000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000243b3b57deeb4f647bea6caa36333c816d7b46fdcb05f9466ecacc140ea8c66faf15b3d9f100000000000000000000000000000000000000000000000000000000.json
 #[cfg(test)]
@@ -228,11 +538,60 @@ mod tests {
         body::Body,
         http::{Request, StatusCode},
     };
+    use async_trait::async_trait;
     use ethers::abi::AbiParser;
     use ethers::contract::BaseContract;
     use serde_json::{json, Value};
     use tower::ServiceExt; // for `oneshot` and `ready`
 
+    struct SlowHandler;
+
+    #[async_trait]
+    impl CCIPReadHandler for SlowHandler {
+        async fn call(
+            &self,
+            _args: Vec<Token>,
+            _call: RPCCall,
+        ) -> Result<Vec<Token>, CCIPReadMiddlewareError> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(vec![])
+        }
+    }
+
+    struct CacheableHandler;
+
+    #[async_trait]
+    impl CCIPReadHandler for CacheableHandler {
+        async fn call(
+            &self,
+            _args: Vec<Token>,
+            _call: RPCCall,
+        ) -> Result<Vec<Token>, CCIPReadMiddlewareError> {
+            Ok(vec![Token::Bool(true)])
+        }
+
+        fn ttl(&self) -> Option<Duration> {
+            Some(Duration::from_secs(60))
+        }
+    }
+
+    struct SignedHandler;
+
+    #[async_trait]
+    impl CCIPReadHandler for SignedHandler {
+        async fn call(
+            &self,
+            _args: Vec<Token>,
+            _call: RPCCall,
+        ) -> Result<Vec<Token>, CCIPReadMiddlewareError> {
+            Ok(vec![Token::Bool(true)])
+        }
+
+        fn signed(&self) -> bool {
+            true
+        }
+    }
+
     #[test]
     fn it_parse_offchain_resolver_abi() {
         let abi = AbiParser::default().parse_str(r#"[
@@ -260,4 +619,232 @@ mod tests {
             json!({ "message": "No implementation for function with selector 0x9061b923"})
         );
     }
+
+    #[tokio::test]
+    async fn test_gateway_get_times_out_on_slow_handler() {
+        let abi = AbiParser::default()
+            .parse_str(r#"[function testFn() external view returns(bool)]"#)
+            .unwrap();
+        let selector = abi.function("testFn").unwrap().short_signature();
+
+        let mut server = Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080)
+            .with_timeout(Duration::from_millis(10));
+        server.add(abi, "testFn", Arc::new(SlowHandler)).unwrap();
+        let router = server.router();
+
+        let calldata = format!("0x{}", hex::encode(selector));
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/gateway/0x8464135c8f25da09e49bc8782676a84730c318bc/{calldata}.json"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_gateway_get_returns_not_modified_for_matching_etag() {
+        let abi = AbiParser::default()
+            .parse_str(r#"[function testFn() external view returns(bool)]"#)
+            .unwrap();
+        let selector = abi.function("testFn").unwrap().short_signature();
+
+        let mut server = Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080);
+        server
+            .add(abi, "testFn", Arc::new(CacheableHandler))
+            .unwrap();
+        let router = server.router();
+
+        let calldata = format!("0x{}", hex::encode(selector));
+        let uri = format!("/gateway/0x8464135c8f25da09e49bc8782676a84730c318bc/{calldata}.json");
+
+        let first = router
+            .clone()
+            .oneshot(Request::builder().uri(&uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .unwrap()
+            .to_string();
+
+        let second = router
+            .oneshot(
+                Request::builder()
+                    .uri(&uri)
+                    .header(IF_NONE_MATCH, etag)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    #[tokio::test]
+    async fn test_batch_gateway_marks_per_entry_failures() {
+        let abi = AbiParser::default()
+            .parse_str(r#"[function testFn() external view returns(bool)]"#)
+            .unwrap();
+        let selector = abi.function("testFn").unwrap().short_signature();
+
+        let mut server =
+            Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080).with_batch_gateway();
+        server
+            .add(abi, "testFn", Arc::new(CacheableHandler))
+            .unwrap();
+        let router = server.router();
+
+        let sender = Address::from_str("0x8464135c8f25da09e49bc8782676a84730c318bc").unwrap();
+        let queries = Token::Array(vec![
+            Token::Tuple(vec![
+                Token::Address(sender),
+                Token::Bytes(Vec::new()),
+                Token::Bytes(selector.to_vec()),
+            ]),
+            Token::Tuple(vec![
+                Token::Address(sender),
+                Token::Bytes(Vec::new()),
+                Token::Bytes(vec![0xde, 0xad, 0xbe, 0xef]),
+            ]),
+            Token::Tuple(vec![
+                Token::Address(sender),
+                Token::Bytes(Vec::new()),
+                Token::Bytes(vec![0xde, 0xad]),
+            ]),
+        ]);
+        let mut calldata = BATCH_GATEWAY_SELECTOR.to_vec();
+        calldata.extend(abi::encode(&[queries]));
+
+        let uri = format!("/gateway/{sender:?}/0x{}.json", hex::encode(&calldata));
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        let data = body["data"].as_str().unwrap();
+        let encoded = Bytes::from_str(data).unwrap();
+        let tokens = abi::decode(
+            &[
+                ParamType::Array(Box::new(ParamType::Bool)),
+                ParamType::Array(Box::new(ParamType::Bytes)),
+            ],
+            &encoded,
+        )
+        .unwrap();
+        let failures = tokens[0].clone().into_array().unwrap();
+        assert_eq!(
+            failures,
+            vec![Token::Bool(false), Token::Bool(true), Token::Bool(true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_allows_content_type() {
+        let server = Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080)
+            .with_cors(CorsConfig::default());
+        let router = server.router();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("OPTIONS")
+                    .uri("/gateway")
+                    .header(axum::http::header::ORIGIN, "https://example.com")
+                    .header(axum::http::header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+                    .header(
+                        axum::http::header::ACCESS_CONTROL_REQUEST_HEADERS,
+                        "content-type",
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let allow_headers = response
+            .headers()
+            .get(axum::http::header::ACCESS_CONTROL_ALLOW_HEADERS)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        assert!(allow_headers.contains("content-type"));
+    }
+
+    #[tokio::test]
+    async fn test_gateway_post_accepts_ccip_read_client_body() {
+        let server = Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080);
+        let router = server.router();
+
+        let body = serde_json::to_vec(&RPCCall {
+            to: "0x8464135c8f25da09e49bc8782676a84730c318bc".to_string(),
+            data: Bytes::from_str("0x9061b923").unwrap(),
+        })
+        .unwrap();
+
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/gateway")
+                    .header(axum::http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "message": "No implementation for function with selector 0x9061b923"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_handler_without_signer_errors() {
+        let abi = AbiParser::default()
+            .parse_str(r#"[function testFn() external view returns(bool)]"#)
+            .unwrap();
+        let selector = abi.function("testFn").unwrap().short_signature();
+
+        let mut server = Server::new(IpAddr::V4("127.0.0.1".parse().unwrap()), 8080);
+        server.add(abi, "testFn", Arc::new(SignedHandler)).unwrap();
+        let router = server.router();
+
+        let calldata = format!("0x{}", hex::encode(selector));
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .uri(format!(
+                        "/gateway/0x8464135c8f25da09e49bc8782676a84730c318bc/{calldata}.json"
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "message": "Handler requires a signed response but the server has no signer configured" })
+        );
+    }
 }
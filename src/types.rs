@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use ethers_core::abi::Token;
+use ethers_core::types::Bytes;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::CCIPReadMiddlewareError;
+
+/// A single CCIP-Read RPC call, as submitted by a client against the
+/// `/gateway` routes.
+#[derive(Debug, Clone, Serialize)]
+pub struct RPCCall {
+    #[serde(rename = "sender")]
+    pub to: String,
+    pub data: Bytes,
+}
+
+/// The HTTP-level response produced by the gateway for a given [`RPCCall`].
+#[derive(Debug, Clone)]
+pub struct RPCResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+/// Implemented by handlers registered with [`crate::Server::add`] to resolve
+/// the arguments decoded from an `RPCCall` into the function's return values.
+#[async_trait]
+pub trait CCIPReadHandler {
+    async fn call(
+        &self,
+        args: Vec<Token>,
+        call: RPCCall,
+    ) -> Result<Vec<Token>, CCIPReadMiddlewareError>;
+
+    /// Whether this handler's responses are signed per the ENS
+    /// offchain-resolver `SignatureVerifier` scheme, using the signer
+    /// configured via [`crate::Server::with_signer`]. If the server has no
+    /// signer, calls to such a handler fail with
+    /// [`CCIPReadMiddlewareError::MissingSigner`]. Defaults to `false` so
+    /// existing handlers are unaffected.
+    fn signed(&self) -> bool {
+        false
+    }
+
+    /// Optional cache lifetime for this handler's responses. Declaring a
+    /// TTL lets [`crate::Server`] emit `Cache-Control`/`ETag` headers and
+    /// serve conditional `GET` requests instead of recomputing the result.
+    /// Defaults to `None` (not cacheable).
+    fn ttl(&self) -> Option<Duration> {
+        None
+    }
+}